@@ -10,4 +10,10 @@ pub enum Error {
     InvalidAddress,
     #[error("Reserved address cannot be used as a network: {0}")]
     ReservedAddress(ReservedAddress),
+    #[error("Address has host bits set and is not the canonical network address")]
+    NonCanonicalAddress,
+    #[error("Not enough address space remains in the parent network to satisfy the request")]
+    InsufficientSpace,
+    #[error("Buffer was too short to contain a complete encoded network")]
+    Truncated,
 }
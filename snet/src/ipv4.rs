@@ -240,6 +240,20 @@ impl TryFrom<&str> for Network {
 }
 
 impl Network {
+    /// Parses a network from CIDR notation, rejecting addresses with
+    /// host bits set below the prefix (e.g. `192.168.147.5/28`) instead
+    /// of silently accepting them. Use this when parsing user-supplied
+    /// config, where such an address is almost always a typo.
+    pub fn try_from_strict(value: &str) -> Result<Self> {
+        let network = Self::try_from(value)?;
+
+        if network.is_valid() {
+            Ok(network)
+        } else {
+            Err(Error::NonCanonicalAddress)
+        }
+    }
+
     fn from_dotted_decimal_parts(
         octal1: u8,
         octal2: u8,
@@ -276,14 +290,6 @@ impl Network {
             .map(|network_len| !0x0 << (32 - network_len))
     }
 
-    pub fn subnet_mask(&self) -> u32 {
-        if self.subnet_mask_len == 0 {
-            0x0
-        } else {
-            !0x0 << (32 - self.subnet_mask_len)
-        }
-    }
-
     pub fn num_subnets(&self) -> Option<u32> {
         let net_mask = match self.net_mask() {
             Some(n) => n,
@@ -347,8 +353,129 @@ impl Network {
 
         Box::new(net_iter.chain(hosts).chain(net_broadcast_iter))
     }
+
+    /// Carves this network into variable-length subnets (VLSM) sized to
+    /// a list of host demands. Demands are served largest-first, each
+    /// one taking the most specific (longest) prefix whose host
+    /// capacity still meets it, placed at the next free, correctly
+    /// aligned offset within this network.
+    pub fn allocate(&self, host_demands: &[u32]) -> Result<Vec<Network>> {
+        let mut demands = host_demands.to_vec();
+        demands.sort_unstable_by(|a, b| b.cmp(a));
+
+        let parent_start = u64::from(self.address & self.subnet_mask());
+        let parent_end = parent_start + u64::from(!self.subnet_mask()) + 1;
+
+        let mut cursor = parent_start;
+        let mut allocated = Vec::with_capacity(demands.len());
+
+        for demand in demands {
+            let subnet_mask_len = Self::smallest_prefix_for(demand)?;
+            let block_size = 1u64 << (32 - subnet_mask_len as u32);
+            let aligned_cursor = align_up(cursor, block_size);
+
+            if aligned_cursor + block_size > parent_end {
+                return Err(Error::InsufficientSpace);
+            }
+
+            allocated.push(Self {
+                address: aligned_cursor as u32,
+                subnet_mask_len,
+            });
+            cursor = aligned_cursor + block_size;
+        }
+
+        Ok(allocated)
+    }
+
+    /// The most specific (largest) prefix length whose
+    /// `num_hosts_per_subnet` still meets `demand`.
+    fn smallest_prefix_for(demand: u32) -> Result<u8> {
+        (0u8..=30u8)
+            .rev()
+            .find(|prefix| (1u64 << (32 - *prefix as u32)) - 2 >= u64::from(demand))
+            .ok_or(Error::InsufficientSpace)
+    }
+}
+
+fn align_up(value: u64, align: u64) -> u64 {
+    let remainder = value % align;
+    if remainder == 0 {
+        value
+    } else {
+        value + (align - remainder)
+    }
+}
+
+/// Aggregates (route-summarizes) a list of CIDR blocks into the fewest
+/// enclosing supernets, the standard inverse of subnetting. Canonicalizes
+/// and sorts the inputs, then repeatedly drops any block already
+/// contained in another and merges equal-length "buddy" blocks into
+/// their shared parent prefix, until no further reduction is possible.
+pub fn summarize(networks: &[Network]) -> Vec<Network> {
+    let mut blocks: Vec<Network> = networks.iter().map(Network::canonical).collect();
+
+    loop {
+        blocks.sort_by_key(|n| (n.address, n.subnet_mask_len));
+        blocks.dedup();
+
+        let covered: Vec<Network> = blocks
+            .iter()
+            .filter(|candidate| {
+                !blocks.iter().any(|other| {
+                    other.subnet_mask_len < candidate.subnet_mask_len && other.contains(candidate)
+                })
+            })
+            .copied()
+            .collect();
+
+        let mut merged = Vec::with_capacity(covered.len());
+        let mut changed = false;
+        let mut i = 0;
+
+        while i < covered.len() {
+            if let Some(next) = covered.get(i + 1) {
+                if let Some(parent) = merge_buddies(&covered[i], next) {
+                    merged.push(parent);
+                    i += 2;
+                    changed = true;
+                    continue;
+                }
+            }
+            merged.push(covered[i]);
+            i += 1;
+        }
+
+        if !changed {
+            return merged;
+        }
+        blocks = merged;
+    }
+}
+
+/// Merges two equal-length networks into their shared `/(n-1)` parent
+/// if they are buddies, i.e. `a` is the low half and `b` is the high
+/// half of that parent block.
+fn merge_buddies(a: &Network, b: &Network) -> Option<Network> {
+    if a.subnet_mask_len == 0 || a.subnet_mask_len != b.subnet_mask_len {
+        return None;
+    }
+
+    let block_size = 1u64 << (32 - a.subnet_mask_len as u32);
+    let a_address = u64::from(a.address);
+
+    if a_address % (2 * block_size) == 0 && u64::from(b.address) == a_address + block_size {
+        Some(Network {
+            address: a.address,
+            subnet_mask_len: a.subnet_mask_len - 1,
+        })
+    } else {
+        None
+    }
 }
 
+crate::cidr_network_impl!(Network, Address, u32, 32);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -517,4 +644,200 @@ mod test {
         assert_eq!(&addresses[0], "class D network");
         assert_eq!(addresses.len(), 1);
     }
+
+    #[test]
+    fn test_contains_equal_prefix() {
+        let a = Network::try_from("192.168.147.0/28").unwrap();
+        let b = Network::try_from("192.168.147.0/28").unwrap();
+        let c = Network::try_from("192.168.147.16/28").unwrap();
+
+        assert!(a.contains(&b));
+        assert!(!a.contains(&c));
+    }
+
+    #[test]
+    fn test_contains_zero_prefix() {
+        let any = Network::try_from("0.0.0.0/0").unwrap();
+        let other = Network::try_from("192.168.147.0/28").unwrap();
+
+        assert!(any.contains(&other));
+        assert!(!other.contains(&any));
+    }
+
+    #[test]
+    fn test_contains_cross_octet_boundary() {
+        let parent = Network::try_from("192.168.0.0/15").unwrap();
+        let child = Network::try_from("192.169.147.0/28").unwrap();
+        let unrelated = Network::try_from("192.170.0.0/16").unwrap();
+
+        assert!(parent.contains(&child));
+        assert!(!parent.contains(&unrelated));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let a = Network::try_from("192.168.147.0/28").unwrap();
+        let b = Network::try_from("192.168.147.0/30").unwrap();
+        let c = Network::try_from("192.168.148.0/28").unwrap();
+
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+        assert!(!a.overlaps(&c));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(Network::try_from("192.168.147.0/28").unwrap().is_valid());
+        assert!(!Network::try_from("192.168.147.5/28").unwrap().is_valid());
+        assert!(Network::try_from("192.168.147.5/32").unwrap().is_valid());
+    }
+
+    #[test]
+    fn test_canonical() {
+        let network = Network::try_from("192.168.147.5/28").unwrap();
+        let canonical = network.canonical();
+
+        assert!(canonical.is_valid());
+        assert_eq!(canonical.address, 0b11000000_10101000_10010011_00000000);
+        assert_eq!(canonical.subnet_mask_len, 28);
+    }
+
+    #[test]
+    fn test_network_and_broadcast_address() {
+        let network = Network::try_from("192.168.147.0/28").unwrap();
+
+        assert_eq!(network.network_address().to_string(), "192.168.147.0");
+        assert_eq!(network.broadcast_address().to_string(), "192.168.147.15");
+    }
+
+    #[test]
+    fn test_try_from_strict() {
+        assert_matches!(
+            Network::try_from_strict("192.168.147.5/28"),
+            Err(Error::NonCanonicalAddress)
+        );
+
+        assert!(Network::try_from_strict("192.168.147.0/28").is_ok());
+    }
+
+    #[test]
+    fn test_allocate_vlsm() {
+        let network = Network::try_from("192.168.1.0/24").unwrap();
+        let subnets = network.allocate(&[2, 120, 12, 60]).unwrap();
+
+        let rendered: Vec<(String, u8)> = subnets
+            .iter()
+            .map(|s| (s.network_address().to_string(), s.subnet_mask_len()))
+            .collect();
+
+        assert_eq!(
+            rendered,
+            vec![
+                ("192.168.1.0".to_string(), 25),
+                ("192.168.1.128".to_string(), 26),
+                ("192.168.1.192".to_string(), 28),
+                ("192.168.1.208".to_string(), 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_allocate_insufficient_space() {
+        let network = Network::try_from("192.168.1.0/30").unwrap();
+
+        assert_matches!(
+            network.allocate(&[120]),
+            Err(Error::InsufficientSpace)
+        );
+    }
+
+    #[test]
+    fn test_wire_format_round_trip() {
+        let network = Network::try_from("192.168.147.0/28").unwrap();
+
+        let mut buf = [0u8; 5];
+        let written = network.write_to(&mut buf).unwrap();
+        assert_eq!(written, 5);
+        assert_eq!(buf, [28, 192, 168, 147, 0]);
+
+        let (decoded, read) = Network::read_from(&buf).unwrap();
+        assert_eq!(read, 5);
+        assert_eq!(decoded, network);
+    }
+
+    #[test]
+    fn test_wire_format_truncated() {
+        let buf = [28, 192, 168, 147];
+        assert_matches!(Network::read_from(&buf), Err(Error::Truncated));
+    }
+
+    #[test]
+    fn test_write_to_truncated() {
+        let network = Network::try_from("192.168.147.0/28").unwrap();
+        let mut buf = [0u8; 4];
+        assert_matches!(network.write_to(&mut buf), Err(Error::Truncated));
+    }
+
+    #[test]
+    fn test_wire_format_invalid_subnet_mask() {
+        let buf = [33, 192, 168, 147, 0];
+        assert_matches!(Network::read_from(&buf), Err(Error::InvalidSubnetMask));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let network = Network::try_from("192.168.147.0/28").unwrap();
+        let json = ::serde_json::to_string(&network).unwrap();
+        assert_eq!(json, "\"192.168.147.0/28\"");
+
+        let decoded: Network = ::serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, network);
+    }
+
+    #[test]
+    fn test_summarize_merges_buddies() {
+        let networks = [
+            Network::try_from("192.168.0.0/25").unwrap(),
+            Network::try_from("192.168.0.128/25").unwrap(),
+        ];
+
+        let summarized = summarize(&networks);
+        assert_eq!(summarized, vec![Network::try_from("192.168.0.0/24").unwrap()]);
+    }
+
+    #[test]
+    fn test_summarize_drops_contained_blocks() {
+        let networks = [
+            Network::try_from("192.168.0.0/24").unwrap(),
+            Network::try_from("192.168.0.0/28").unwrap(),
+        ];
+
+        let summarized = summarize(&networks);
+        assert_eq!(summarized, vec![Network::try_from("192.168.0.0/24").unwrap()]);
+    }
+
+    #[test]
+    fn test_summarize_leaves_unrelated_blocks_unmerged() {
+        let networks = [
+            Network::try_from("192.168.0.0/25").unwrap(),
+            Network::try_from("192.168.1.128/25").unwrap(),
+        ];
+
+        let summarized = summarize(&networks);
+        assert_eq!(summarized, networks);
+    }
+
+    #[test]
+    fn test_summarize_cascades_merges() {
+        let networks = [
+            Network::try_from("10.0.0.0/26").unwrap(),
+            Network::try_from("10.0.0.64/26").unwrap(),
+            Network::try_from("10.0.0.128/26").unwrap(),
+            Network::try_from("10.0.0.192/26").unwrap(),
+        ];
+
+        let summarized = summarize(&networks);
+        assert_eq!(summarized, vec![Network::try_from("10.0.0.0/24").unwrap()]);
+    }
 }
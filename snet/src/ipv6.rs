@@ -0,0 +1,190 @@
+use crate::{Error, Result};
+use ::std::{
+    convert::TryFrom,
+    fmt::{Binary, Debug, Display},
+    net::Ipv6Addr,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Address(u128);
+
+impl Debug for Address {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{:0128b} - {}", self.0, self)
+    }
+}
+
+impl Display for Address {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{}", Ipv6Addr::from(self.0))
+    }
+}
+
+impl Binary for Address {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{:0128b}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Network {
+    /// The base network address.
+    address: u128,
+
+    /// The length of the subnet prefix, in bits (0-128).
+    subnet_mask_len: u8,
+}
+
+impl Display for Network {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{}/{}", Ipv6Addr::from(self.address), self.subnet_mask_len)
+    }
+}
+
+impl TryFrom<&str> for Network {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let mut parts = value.split('/');
+        let address: Ipv6Addr = parts
+            .next()
+            .ok_or(Error::InvalidAddress)?
+            .parse()
+            .map_err(|_| Error::InvalidAddress)?;
+        let subnet_mask_len = parts
+            .next()
+            .ok_or(Error::InvalidAddress)?
+            .parse()
+            .map_err(|_| Error::InvalidAddress)?;
+
+        if subnet_mask_len > 128 {
+            return Err(Error::InvalidSubnetMask);
+        }
+
+        Ok(Self {
+            address: u128::from(address),
+            subnet_mask_len,
+        })
+    }
+}
+
+crate::cidr_network_impl!(Network, Address, u128, 128);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::assert_matches::assert_matches;
+
+    #[test]
+    fn test_network_from_string() {
+        let network = Network::try_from("2001:db8::/48").unwrap();
+        assert_eq!(network.address, 0x2001_0db8_0000_0000_0000_0000_0000_0000);
+        assert_eq!(network.subnet_mask_len, 48);
+    }
+
+    #[test]
+    fn test_invalid_prefix_length() {
+        assert!(matches!(
+            Network::try_from("2001:db8::/129"),
+            Err(Error::InvalidSubnetMask)
+        ));
+    }
+
+    #[test]
+    fn test_network_display() {
+        let network = Network::try_from("2001:db8::/48").unwrap();
+        assert_eq!("2001:db8::/48", &network.to_string());
+    }
+
+    #[test]
+    fn test_subnet_mask() {
+        let network = Network::try_from("2001:db8::/48").unwrap();
+        assert_eq!(
+            network.subnet_mask(),
+            0xffff_ffff_ffff_0000_0000_0000_0000_0000
+        );
+    }
+
+    #[test]
+    fn test_hosts_iter() {
+        let network = Network::try_from("2001:db8::/126").unwrap();
+        let hosts: Vec<String> = network.hosts().map(|a| a.to_string()).collect();
+
+        assert_eq!(hosts, vec!["2001:db8::1", "2001:db8::2"]);
+    }
+
+    #[test]
+    fn test_is_valid_and_canonical() {
+        let network = Network::try_from("2001:db8::1/48").unwrap();
+        assert!(!network.is_valid());
+
+        let canonical = network.canonical();
+        assert!(canonical.is_valid());
+        assert_eq!(canonical.to_string(), "2001:db8::/48");
+    }
+
+    #[test]
+    fn test_network_and_broadcast_address() {
+        let network = Network::try_from("2001:db8::/126").unwrap();
+
+        assert_eq!(network.network_address().to_string(), "2001:db8::");
+        assert_eq!(network.broadcast_address().to_string(), "2001:db8::3");
+    }
+
+    #[test]
+    fn test_subnets_at() {
+        let network = Network::try_from("2001:db8::/46").unwrap();
+        let subnets: Vec<String> = network
+            .subnets_at(48)
+            .unwrap()
+            .map(|n| n.to_string())
+            .collect();
+
+        assert_eq!(
+            subnets,
+            vec![
+                "2001:db8::/48",
+                "2001:db8:1::/48",
+                "2001:db8:2::/48",
+                "2001:db8:3::/48",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wire_format_round_trip() {
+        let network = Network::try_from("2001:db8::/48").unwrap();
+
+        let mut buf = [0u8; 17];
+        let written = network.write_to(&mut buf).unwrap();
+        assert_eq!(written, 17);
+
+        let (decoded, read) = Network::read_from(&buf).unwrap();
+        assert_eq!(read, 17);
+        assert_eq!(decoded, network);
+    }
+
+    #[test]
+    fn test_wire_format_truncated() {
+        let buf = [48u8; 10];
+        assert_matches!(Network::read_from(&buf), Err(Error::Truncated));
+    }
+
+    #[test]
+    fn test_write_to_truncated() {
+        let network = Network::try_from("2001:db8::/48").unwrap();
+        let mut buf = [0u8; 16];
+        assert_matches!(network.write_to(&mut buf), Err(Error::Truncated));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let network = Network::try_from("2001:db8::/48").unwrap();
+        let json = ::serde_json::to_string(&network).unwrap();
+        assert_eq!(json, "\"2001:db8::/48\"");
+
+        let decoded: Network = ::serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, network);
+    }
+}
@@ -0,0 +1,6 @@
+mod errors;
+mod macros;
+pub mod ipv4;
+pub mod ipv6;
+
+pub use errors::{Error, Result};
@@ -0,0 +1,190 @@
+/// Generates the protocol-agnostic CIDR arithmetic shared between
+/// [`crate::ipv4::Network`] and [`crate::ipv6::Network`].
+///
+/// The recurrences for mask computation and address enumeration are
+/// identical across address families and differ only in the backing
+/// integer type (`$int`) and its bit width (`$bits`); this macro writes
+/// that logic once per invocation instead of once per protocol.
+#[macro_export]
+macro_rules! cidr_network_impl {
+    ($network:ty, $addr:ident, $int:ty, $bits:expr) => {
+        impl $network {
+            /// The length of this network's subnet mask, in bits.
+            pub fn subnet_mask_len(&self) -> u8 {
+                self.subnet_mask_len
+            }
+
+            /// The subnet mask implied by `subnet_mask_len`, in the same
+            /// integer representation as the address.
+            pub fn subnet_mask(&self) -> $int {
+                if self.subnet_mask_len == 0 {
+                    0
+                } else {
+                    <$int>::MAX << ($bits - self.subnet_mask_len as u32)
+                }
+            }
+
+            /// Iterates every host address strictly between the network
+            /// and broadcast addresses of this block.
+            pub fn hosts(&self) -> impl Iterator<Item = $addr> {
+                let subnet_mask = self.subnet_mask();
+                let network_address = self.address & subnet_mask;
+                let broadcast_address = network_address | !subnet_mask;
+                let num_hosts = broadcast_address.saturating_sub(network_address);
+
+                (1..num_hosts).map(move |i| $addr(network_address + i))
+            }
+
+            /// Returns `true` if no host bits below the prefix are set,
+            /// i.e. this is already the canonical network address.
+            pub fn is_valid(&self) -> bool {
+                if self.subnet_mask_len as u32 == $bits {
+                    true
+                } else {
+                    self.address & (<$int>::MAX >> self.subnet_mask_len) == 0
+                }
+            }
+
+            /// Returns this network with its host bits zeroed.
+            pub fn canonical(&self) -> Self {
+                Self {
+                    address: self.address & self.subnet_mask(),
+                    subnet_mask_len: self.subnet_mask_len,
+                }
+            }
+
+            /// The masked base address of this network.
+            pub fn network_address(&self) -> $addr {
+                $addr(self.address & self.subnet_mask())
+            }
+
+            /// The broadcast address of this network, i.e. the network
+            /// address with every host bit set.
+            pub fn broadcast_address(&self) -> $addr {
+                $addr(self.network_address().0 | !self.subnet_mask())
+            }
+
+            /// Returns `true` if `self` fully encloses `other`, i.e.
+            /// every address in `other` also lies within `self`. This is
+            /// pure prefix math over the stored address and mask length,
+            /// so it applies uniformly regardless of class. Networks with
+            /// equal prefix lengths are compared by their stored
+            /// addresses, host bits included.
+            pub fn contains(&self, other: &Self) -> bool {
+                if self.subnet_mask_len > other.subnet_mask_len {
+                    return false;
+                }
+                if self.subnet_mask_len == other.subnet_mask_len {
+                    return self.address == other.address;
+                }
+                if self.subnet_mask_len == 0 {
+                    return true;
+                }
+
+                let shift = $bits - self.subnet_mask_len as u32;
+                (self.address >> shift) == (other.address >> shift)
+            }
+
+            /// Returns `true` if `self` and `other` overlap in either
+            /// direction, i.e. one contains the other.
+            pub fn overlaps(&self, other: &Self) -> bool {
+                self.contains(other) || other.contains(self)
+            }
+
+            /// Splits this network into the child blocks of
+            /// `new_subnet_mask_len`, erroring if the new prefix is not
+            /// at least as specific as this network's own, or exceeds
+            /// the address width.
+            pub fn subnets_at(
+                &self,
+                new_subnet_mask_len: u8,
+            ) -> $crate::Result<impl Iterator<Item = Self>> {
+                if new_subnet_mask_len < self.subnet_mask_len || new_subnet_mask_len as u32 > $bits
+                {
+                    return Err($crate::Error::InvalidSubnetMask);
+                }
+
+                let network_address = self.address & self.subnet_mask();
+                let block_size: $int = if new_subnet_mask_len as u32 == $bits {
+                    1
+                } else {
+                    1 << ($bits - new_subnet_mask_len as u32)
+                };
+                let num_children: $int = 1 << (new_subnet_mask_len - self.subnet_mask_len);
+
+                Ok((0..num_children).map(move |i| Self {
+                    address: network_address + i * block_size,
+                    subnet_mask_len: new_subnet_mask_len,
+                }))
+            }
+
+            /// Encodes this network as one prefix-length byte followed
+            /// by the big-endian address bytes, writing into the front
+            /// of `buf` and returning the number of bytes written.
+            /// Errors with [`crate::Error::Truncated`] if `buf` is
+            /// too short to hold the encoded network.
+            pub fn write_to(&self, buf: &mut [u8]) -> $crate::Result<usize> {
+                let encoded_len = 1 + $bits as usize / 8;
+                if buf.len() < encoded_len {
+                    return Err($crate::Error::Truncated);
+                }
+
+                buf[0] = self.subnet_mask_len;
+                buf[1..encoded_len].copy_from_slice(&self.address.to_be_bytes());
+                Ok(encoded_len)
+            }
+
+            /// Decodes a network from the wire format written by
+            /// [`Self::write_to`], returning the network and the number
+            /// of bytes consumed from `buf`.
+            pub fn read_from(buf: &[u8]) -> $crate::Result<(Self, usize)> {
+                let encoded_len = 1 + $bits as usize / 8;
+                if buf.len() < encoded_len {
+                    return Err($crate::Error::Truncated);
+                }
+
+                let subnet_mask_len = buf[0];
+                if subnet_mask_len as u32 > $bits {
+                    return Err($crate::Error::InvalidSubnetMask);
+                }
+
+                let mut address_bytes = [0u8; $bits as usize / 8];
+                address_bytes.copy_from_slice(&buf[1..encoded_len]);
+
+                Ok((
+                    Self {
+                        address: <$int>::from_be_bytes(address_bytes),
+                        subnet_mask_len,
+                    },
+                    encoded_len,
+                ))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $network {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.collect_str(&::std::format_args!(
+                    "{}/{}",
+                    self.network_address(),
+                    self.subnet_mask_len
+                ))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $network {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let cidr =
+                    <::std::string::String as ::serde::Deserialize>::deserialize(deserializer)?;
+                Self::try_from(cidr.as_str()).map_err(::serde::de::Error::custom)
+            }
+        }
+    };
+}
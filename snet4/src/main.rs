@@ -7,6 +7,7 @@ const ARG_NAME_LIST_SNETS: &str = "list-snets";
 const ARG_NAME_LIST_ALL: &str = "list-all";
 const ARG_NAME_FMT_BINARY: &str = "binary";
 const ARG_NAME_FMT_DECIMAL: &str = "decimal";
+const ARG_NAME_VLSM: &str = "vlsm";
 
 fn main() {
     let matches = App::new("snet4")
@@ -56,6 +57,18 @@ fn main() {
                 "})
                 .conflicts_with(ARG_NAME_LIST_SNETS),
         )
+        .arg(
+            Arg::with_name(ARG_NAME_VLSM)
+                .long(ARG_NAME_VLSM)
+                .required(false)
+                .takes_value(true)
+                .value_name("HOSTS")
+                .help(indoc! {"
+                    Carves the network into variable length subnets
+                    (VLSM) sized to a comma separated list of host
+                    demands, e.g. --vlsm 120,60,12,2
+                "}),
+        )
         .arg(
             Arg::with_name("network")
                 .index(1)
@@ -72,7 +85,21 @@ fn main() {
     let fmt_binary = matches.is_present(ARG_NAME_FMT_BINARY);
     let fmt_decimal = matches.is_present(ARG_NAME_FMT_DECIMAL);
 
-    if matches.is_present(ARG_NAME_LIST_SNETS) {
+    if let Some(hosts) = matches.value_of(ARG_NAME_VLSM) {
+        let host_demands: Vec<u32> = hosts
+            .split(',')
+            .map(|s| {
+                s.trim()
+                    .parse()
+                    .unwrap_or_else(|_| panic!("invalid host count: {}", s))
+            })
+            .collect();
+
+        match network.allocate(&host_demands) {
+            Err(e) => panic!("{}", e),
+            Ok(subnets) => allocate_vlsm(&subnets, fmt_binary, fmt_decimal),
+        }
+    } else if matches.is_present(ARG_NAME_LIST_SNETS) {
         list_subnets(&network, fmt_binary, fmt_decimal);
     } else if matches.is_present(ARG_NAME_LIST_ALL) {
         list_all(&network, fmt_binary, fmt_decimal);
@@ -81,6 +108,27 @@ fn main() {
     }
 }
 
+fn allocate_vlsm(subnets: &[Network], fmt_binary: bool, fmt_decimal: bool) {
+    for subnet in subnets {
+        let address = subnet.network_address();
+
+        if fmt_binary {
+            print!("{:b}", address);
+        }
+        if fmt_binary && fmt_decimal {
+            print!(" - ")
+        }
+        if fmt_decimal || !fmt_binary {
+            print!("{}", address);
+        }
+        println!(
+            "/{} ({} hosts)",
+            subnet.subnet_mask_len(),
+            subnet.num_hosts_per_subnet()
+        );
+    }
+}
+
 fn list_subnets(network: &Network, fmt_binary: bool, fmt_decimal: bool) {
     for address in network.subnets() {
         if fmt_binary {